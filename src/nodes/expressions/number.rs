@@ -1,10 +1,42 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct DigitSeparators {
+    integer: Vec<u32>,
+    fractional: Vec<u32>,
+    exponent: Vec<u32>,
+}
+
+impl Eq for DigitSeparators {}
+
+impl DigitSeparators {
+    pub fn is_empty(&self) -> bool {
+        self.integer.is_empty() && self.fractional.is_empty() && self.exponent.is_empty()
+    }
+
+    #[inline]
+    pub fn integer(&self) -> &[u32] {
+        &self.integer
+    }
+
+    #[inline]
+    pub fn fractional(&self) -> &[u32] {
+        &self.fractional
+    }
+
+    #[inline]
+    pub fn exponent(&self) -> &[u32] {
+        &self.exponent
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct DecimalNumber {
     float: f64,
     exponent: Option<(i64, bool)>,
+    separators: DigitSeparators,
+    exact_integer: Option<i64>,
 }
 
 impl Eq for DecimalNumber {}
@@ -14,6 +46,8 @@ impl DecimalNumber {
         Self {
             float: value,
             exponent: None,
+            separators: DigitSeparators::default(),
+            exact_integer: None,
         }
     }
 
@@ -22,6 +56,19 @@ impl DecimalNumber {
         self
     }
 
+    pub fn with_separators(mut self, separators: DigitSeparators) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Records the exact integer value of a literal that has no fractional
+    /// part and no exponent, so that constant-folding rules can operate on
+    /// it without going through `f64` and losing precision past 2^53.
+    pub fn with_exact_integer(mut self, value: i64) -> Self {
+        self.exact_integer = Some(value);
+        self
+    }
+
     #[inline]
     pub fn set_uppercase(&mut self, is_uppercase: bool) {
         self.exponent = self.exponent.map(|(exponent, _)| (exponent, is_uppercase));
@@ -42,6 +89,16 @@ impl DecimalNumber {
         self.exponent.map(|(exponent, _)| exponent)
     }
 
+    #[inline]
+    pub fn get_separators(&self) -> &DigitSeparators {
+        &self.separators
+    }
+
+    #[inline]
+    pub fn get_exact_integer(&self) -> Option<i64> {
+        self.exact_integer
+    }
+
     pub fn compute_value(&self) -> f64 {
         if let Some((exponent, _)) = self.exponent {
             self.float * 10_f64.powf(exponent as f64)
@@ -54,8 +111,10 @@ impl DecimalNumber {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HexNumber {
     integer: u64,
-    exponent: Option<(u32, bool)>,
+    fractional: Option<(u64, u32)>,
+    exponent: Option<(i64, bool)>,
     is_x_uppercase: bool,
+    separators: DigitSeparators,
 }
 
 impl HexNumber {
@@ -65,16 +124,31 @@ impl HexNumber {
     ) -> Self {
         Self {
             integer,
+            fractional: None,
             exponent: None,
             is_x_uppercase,
+            separators: DigitSeparators::default(),
         }
     }
 
-    pub fn with_exponent(mut self, exponent: u32, is_uppercase: bool) -> Self {
+    /// Sets the fractional part of the mantissa from the raw value and digit
+    /// count of the hex digits that followed the `.`, so that leading zeros
+    /// (e.g. `0x1.05`) are not lost when computing the value.
+    pub fn with_fractional_part(mut self, fractional: u64, digit_count: u32) -> Self {
+        self.fractional.replace((fractional, digit_count));
+        self
+    }
+
+    pub fn with_exponent(mut self, exponent: i64, is_uppercase: bool) -> Self {
         self.exponent.replace((exponent, is_uppercase));
         self
     }
 
+    pub fn with_separators(mut self, separators: DigitSeparators) -> Self {
+        self.separators = separators;
+        self
+    }
+
     pub fn set_uppercase(&mut self, is_uppercase: bool) {
         self.exponent = self.exponent.map(|(value, _)| (value, is_uppercase));
         self.is_x_uppercase = is_uppercase;
@@ -96,23 +170,86 @@ impl HexNumber {
     }
 
     #[inline]
-    pub fn get_exponent(&self) -> Option<u32> {
+    pub fn get_fractional_part(&self) -> Option<(u64, u32)> {
+        self.fractional
+    }
+
+    #[inline]
+    pub fn get_exponent(&self) -> Option<i64> {
         self.exponent.map(|(value, _)| value)
     }
 
+    #[inline]
+    pub fn get_separators(&self) -> &DigitSeparators {
+        &self.separators
+    }
+
     pub fn compute_value(&self) -> f64 {
+        let fractional_value = self
+            .fractional
+            .map(|(digits, digit_count)| digits as f64 / 16_f64.powi(digit_count as i32))
+            .unwrap_or_default();
+        let mantissa = self.integer as f64 + fractional_value;
+
         if let Some((exponent, _)) = self.exponent {
-            (self.integer * 2_u64.pow(exponent)) as f64
+            mantissa * 2_f64.powf(exponent as f64)
         } else {
-            self.integer as f64
+            mantissa
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinaryNumber {
+    integer: u64,
+    is_b_uppercase: bool,
+    separators: DigitSeparators,
+}
+
+impl BinaryNumber {
+    pub fn new(integer: u64, is_b_uppercase: bool) -> Self {
+        Self {
+            integer,
+            is_b_uppercase,
+            separators: DigitSeparators::default(),
         }
     }
+
+    pub fn with_separators(mut self, separators: DigitSeparators) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    #[inline]
+    pub fn set_uppercase(&mut self, is_uppercase: bool) {
+        self.is_b_uppercase = is_uppercase;
+    }
+
+    #[inline]
+    pub fn is_b_uppercase(&self) -> bool {
+        self.is_b_uppercase
+    }
+
+    #[inline]
+    pub fn get_raw_integer(&self) -> u64 {
+        self.integer
+    }
+
+    #[inline]
+    pub fn get_separators(&self) -> &DigitSeparators {
+        &self.separators
+    }
+
+    pub fn compute_value(&self) -> f64 {
+        self.integer as f64
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum NumberExpression {
     Decimal(DecimalNumber),
     Hex(HexNumber),
+    Binary(BinaryNumber),
 }
 
 impl NumberExpression {
@@ -120,6 +257,7 @@ impl NumberExpression {
         match self {
             Self::Decimal(number) => number.set_uppercase(is_uppercase),
             Self::Hex(number) => number.set_uppercase(is_uppercase),
+            Self::Binary(number) => number.set_uppercase(is_uppercase),
         }
     }
 
@@ -127,7 +265,68 @@ impl NumberExpression {
         match self {
             Self::Decimal(decimal) => decimal.compute_value(),
             Self::Hex(hex) => hex.compute_value(),
+            Self::Binary(binary) => binary.compute_value(),
+        }
+    }
+
+    /// Returns the exact integer value of this literal, or `None` if it is a
+    /// decimal written with a `.` (even `123.`) or an exponent, a hex number
+    /// with fractional digits or a `p` exponent, or a value beyond `i64::MAX`.
+    pub fn get_exact_integer(&self) -> Option<i64> {
+        match self {
+            Self::Decimal(decimal) => decimal.get_exact_integer(),
+            Self::Hex(hex) => (hex.get_fractional_part().is_none() && hex.get_exponent().is_none())
+                .then(|| i64::try_from(hex.get_raw_integer()).ok())
+                .flatten(),
+            Self::Binary(binary) => i64::try_from(binary.get_raw_integer()).ok(),
+        }
+    }
+
+    /// Returns the shortest literal that round-trips to the same `f64` bits
+    /// as this number, or the number unchanged when `enabled` is `false`.
+    ///
+    /// Not yet called from `cli::minify`: this tree has no Lua
+    /// parser/generator to walk number literals with, so the CLI wiring
+    /// described in the ticket can't land until that pipeline exists.
+    pub fn minimal_form(&self, enabled: bool) -> NumberExpression {
+        if !enabled {
+            return self.clone();
+        }
+
+        let value = self.compute_value();
+        let bits = value.to_bits();
+
+        let mut candidates = vec![format!("{value}")];
+
+        let exponential = format!("{value:e}");
+        if !candidates.contains(&exponential) {
+            candidates.push(exponential);
         }
+
+        if value.fract() == 0.0 && (0.0..=u64::MAX as f64).contains(&value) {
+            let integer = value as u64;
+            candidates.push(format!("0x{integer:X}"));
+
+            let mut mantissa = integer;
+            let mut shift = 0_u32;
+            while mantissa != 0 && mantissa & 0xF == 0 {
+                mantissa >>= 4;
+                shift += 4;
+            }
+            if shift > 0 {
+                candidates.push(format!("0x{mantissa:X}p{shift}"));
+            }
+        }
+
+        candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                let number: NumberExpression = candidate.parse().ok()?;
+                (number.compute_value().to_bits() == bits).then_some((candidate.len(), number))
+            })
+            .min_by_key(|(length, _)| *length)
+            .map(|(_, number)| number)
+            .unwrap_or_else(|| self.clone())
     }
 }
 
@@ -143,12 +342,37 @@ impl From<HexNumber> for NumberExpression {
     }
 }
 
+impl From<BinaryNumber> for NumberExpression {
+    fn from(number: BinaryNumber) -> Self {
+        Self::Binary(number)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NumberParsingError {
-    InvalidHexadecimalNumber,
-    InvalidHexadecimalExponent,
-    InvalidDecimalNumber,
-    InvalidDecimalExponent,
+    InvalidHexadecimalNumber(usize),
+    InvalidHexadecimalExponent(usize),
+    InvalidDecimalNumber(usize),
+    InvalidDecimalExponent(usize),
+    InvalidBinaryNumber(usize),
+    InvalidDigitSeparator(usize),
+}
+
+impl NumberParsingError {
+    /// Returns the byte offset, within the parsed literal, where the error
+    /// was detected.
+    pub fn position(&self) -> usize {
+        use NumberParsingError::*;
+
+        match self {
+            InvalidHexadecimalNumber(position)
+            | InvalidHexadecimalExponent(position)
+            | InvalidDecimalNumber(position)
+            | InvalidDecimalExponent(position)
+            | InvalidBinaryNumber(position)
+            | InvalidDigitSeparator(position) => *position,
+        }
+    }
 }
 
 impl Display for NumberParsingError {
@@ -156,78 +380,259 @@ impl Display for NumberParsingError {
         use NumberParsingError::*;
 
         match self {
-            InvalidHexadecimalNumber => write!(f, "could not parse hexadecimal number"),
-            InvalidHexadecimalExponent => write!(f, "could not parse hexadecimal exponent"),
-            InvalidDecimalNumber => write!(f, "could not parse decimal number"),
-            InvalidDecimalExponent => write!(f, "could not parse decimal exponent"),
+            InvalidHexadecimalNumber(position) => {
+                write!(f, "could not parse hexadecimal number at byte {position}")
+            }
+            InvalidHexadecimalExponent(position) => {
+                write!(f, "could not parse hexadecimal exponent at byte {position}")
+            }
+            InvalidDecimalNumber(position) => {
+                write!(f, "could not parse decimal number at byte {position}")
+            }
+            InvalidDecimalExponent(position) => {
+                write!(f, "could not parse decimal exponent at byte {position}")
+            }
+            InvalidBinaryNumber(position) => {
+                write!(f, "could not parse binary number at byte {position}")
+            }
+            InvalidDigitSeparator(position) => {
+                write!(f, "unexpected `_` digit separator at byte {position}")
+            }
+        }
+    }
+}
+
+/// Scans a run of digits matching `is_digit` starting at `*index`, allowing
+/// `_` separators between digits (but not before, after or next to another
+/// separator). Advances `*index` past the run and returns the digits with
+/// separators stripped out, together with the byte offsets (relative to the
+/// cleaned digits) at which a separator was removed, so the original
+/// grouping can be recovered later. Returns the byte offset of a misplaced
+/// separator as an error.
+fn scan_digits(
+    bytes: &[u8],
+    index: &mut usize,
+    is_digit: fn(u8) -> bool,
+) -> Result<(String, Vec<u32>), usize> {
+    let mut digits = String::new();
+    let mut separators = Vec::new();
+    let mut has_digit = false;
+    let mut pending_separator = None;
+
+    while let Some(&byte) = bytes.get(*index) {
+        if is_digit(byte) {
+            digits.push(byte as char);
+            has_digit = true;
+            pending_separator = None;
+            *index += 1;
+        } else if byte == b'_' {
+            if !has_digit || pending_separator.is_some() {
+                return Err(*index);
+            }
+            pending_separator = Some(*index);
+            separators.push(digits.len() as u32);
+            *index += 1;
+        } else {
+            break;
+        }
+    }
+
+    match pending_separator {
+        Some(position) => Err(position),
+        None => Ok((digits, separators)),
+    }
+}
+
+/// Consumes an optional `+`/`-` sign at `*index`, returning whether it was
+/// negative.
+fn scan_sign(bytes: &[u8], index: &mut usize) -> bool {
+    match bytes.get(*index) {
+        Some(b'-') => {
+            *index += 1;
+            true
+        }
+        Some(b'+') => {
+            *index += 1;
+            false
         }
+        _ => false,
     }
 }
 
 impl FromStr for NumberExpression {
     type Err = NumberParsingError;
 
+    /// Walks the literal in a single pass (prefix, then integer digits,
+    /// fractional digits and exponent), instead of searching the whole
+    /// string for marker characters, so that hex digits like `a`-`f` are
+    /// never mistaken for an `e` exponent and every failure points at the
+    /// byte offset that caused it.
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let number = if value.starts_with("0x") || value.starts_with("0X") {
-            let is_x_uppercase = value.chars().nth(1)
-                .map(char::is_uppercase)
-                .unwrap_or(false);
-
-            if let Some(index) = value.find("p") {
-                let exponent = value.get(index + 1..)
-                    .and_then(|string| string.parse().ok())
-                    .ok_or(Self::Err::InvalidHexadecimalExponent)?;
-                let number = u64::from_str_radix(value.get(2..index).unwrap(), 16)
-                    .map_err(|_| Self::Err::InvalidHexadecimalNumber)?;
-
-                HexNumber::new(number, is_x_uppercase)
-                    .with_exponent(exponent, false)
-
-            } else if let Some(index) = value.find("P") {
-                let exponent = value.get(index + 1..)
-                    .and_then(|string| string.parse().ok())
-                    .ok_or(Self::Err::InvalidHexadecimalExponent)?;
-                let number = u64::from_str_radix(value.get(2..index).unwrap(), 16)
-                    .map_err(|_| Self::Err::InvalidHexadecimalNumber)?;
-
-                HexNumber::new(number, is_x_uppercase)
-                    .with_exponent(exponent, true)
+        let bytes = value.as_bytes();
+        let index = &mut 0_usize;
+
+        let number = if bytes.first() == Some(&b'0') && matches!(bytes.get(1), Some(b'x' | b'X')) {
+            let is_x_uppercase = bytes[1] == b'X';
+            *index = 2;
+
+            let (integer_digits, integer_separators) = scan_digits(bytes, index, |byte| byte.is_ascii_hexdigit())
+                .map_err(NumberParsingError::InvalidDigitSeparator)?;
+
+            let mut fractional_digits = None;
+            let mut fractional_separators = Vec::new();
+            if bytes.get(*index) == Some(&b'.') {
+                *index += 1;
+                let (digits, separators) = scan_digits(bytes, index, |byte| byte.is_ascii_hexdigit())
+                    .map_err(NumberParsingError::InvalidDigitSeparator)?;
+                fractional_separators = separators;
+                fractional_digits = (!digits.is_empty()).then_some(digits);
+            }
+
+            if integer_digits.is_empty() && fractional_digits.is_none() {
+                return Err(NumberParsingError::InvalidHexadecimalNumber(*index));
+            }
+
+            let integer = if integer_digits.is_empty() {
+                0
             } else {
-                let number = u64::from_str_radix(value.get(2..)
-                    .unwrap(), 16)
-                    .map_err(|_| Self::Err::InvalidHexadecimalNumber)?;
-
-                HexNumber::new(number, is_x_uppercase)
-            }.into()
+                u64::from_str_radix(&integer_digits, 16)
+                    .map_err(|_| NumberParsingError::InvalidHexadecimalNumber(*index))?
+            };
 
+            let mut number = HexNumber::new(integer, is_x_uppercase);
+
+            if let Some(digits) = &fractional_digits {
+                let fractional = u64::from_str_radix(digits, 16)
+                    .map_err(|_| NumberParsingError::InvalidHexadecimalNumber(*index))?;
+                number = number.with_fractional_part(fractional, digits.len() as u32);
+            }
+
+            let mut exponent_separators = Vec::new();
+            if matches!(bytes.get(*index), Some(b'p' | b'P')) {
+                let is_uppercase = bytes[*index] == b'P';
+                *index += 1;
+
+                let negative = scan_sign(bytes, index);
+                let exponent_start = *index;
+                let (digits, separators) = scan_digits(bytes, index, |byte| byte.is_ascii_digit())
+                    .map_err(NumberParsingError::InvalidDigitSeparator)?;
+                exponent_separators = separators;
+
+                if digits.is_empty() {
+                    return Err(NumberParsingError::InvalidHexadecimalExponent(exponent_start));
+                }
+
+                let magnitude: i64 = digits.parse()
+                    .map_err(|_| NumberParsingError::InvalidHexadecimalExponent(exponent_start))?;
+
+                number = number.with_exponent(if negative { -magnitude } else { magnitude }, is_uppercase);
+            }
+
+            if *index != bytes.len() {
+                return Err(NumberParsingError::InvalidHexadecimalNumber(*index));
+            }
+
+            number.with_separators(DigitSeparators {
+                integer: integer_separators,
+                fractional: fractional_separators,
+                exponent: exponent_separators,
+            }).into()
+        } else if bytes.first() == Some(&b'0') && matches!(bytes.get(1), Some(b'b' | b'B')) {
+            let is_b_uppercase = bytes[1] == b'B';
+            *index = 2;
+
+            let (digits, separators) = scan_digits(bytes, index, |byte| byte == b'0' || byte == b'1')
+                .map_err(NumberParsingError::InvalidDigitSeparator)?;
+
+            if digits.is_empty() || *index != bytes.len() {
+                return Err(NumberParsingError::InvalidBinaryNumber(*index));
+            }
+
+            let integer = u64::from_str_radix(&digits, 2)
+                .map_err(|_| NumberParsingError::InvalidBinaryNumber(*index))?;
+
+            BinaryNumber::new(integer, is_b_uppercase)
+                .with_separators(DigitSeparators {
+                    integer: separators,
+                    ..DigitSeparators::default()
+                })
+                .into()
         } else {
-            if let Some(index) = value.find("e") {
-                let exponent = value.get(index + 1..)
-                    .and_then(|string| string.parse().ok())
-                    .ok_or(Self::Err::InvalidDecimalExponent)?;
-                let number = value.get(0..index)
-                    .and_then(|string| string.parse().ok())
-                    .ok_or(Self::Err::InvalidDecimalNumber)?;
-
-                DecimalNumber::new(number)
-                    .with_exponent(exponent, false)
-
-            } else if let Some(index) = value.find("E") {
-                let exponent: i64 = value.get(index + 1..)
-                    .and_then(|string| string.parse().ok())
-                    .ok_or(Self::Err::InvalidDecimalExponent)?;
-                let number = value.get(0..index)
-                    .and_then(|string| string.parse().ok())
-                    .ok_or(Self::Err::InvalidDecimalNumber)?;
-
-                DecimalNumber::new(number)
-                    .with_exponent(exponent, true)
+            let (integer_digits, integer_separators) = scan_digits(bytes, index, |byte| byte.is_ascii_digit())
+                .map_err(NumberParsingError::InvalidDigitSeparator)?;
+
+            let mut fractional_digits = String::new();
+            let mut fractional_separators = Vec::new();
+            let has_dot = bytes.get(*index) == Some(&b'.');
+            if has_dot {
+                *index += 1;
+                let (digits, separators) = scan_digits(bytes, index, |byte| byte.is_ascii_digit())
+                    .map_err(NumberParsingError::InvalidDigitSeparator)?;
+                fractional_digits = digits;
+                fractional_separators = separators;
+            }
+
+            if integer_digits.is_empty() && fractional_digits.is_empty() {
+                return Err(NumberParsingError::InvalidDecimalNumber(*index));
+            }
+
+            let exact_integer_digits = (!has_dot).then(|| integer_digits.clone());
+
+            let mantissa = if has_dot {
+                format!("{integer_digits}.{fractional_digits}")
             } else {
-                let number = value.parse::<f64>()
-                    .map_err(|_| Self::Err::InvalidDecimalNumber)?;
+                integer_digits
+            };
 
-                DecimalNumber::new(number)
-            }.into()
+            let mut exponent = None;
+            let mut exponent_separators = Vec::new();
+            if matches!(bytes.get(*index), Some(b'e' | b'E')) {
+                let is_uppercase = bytes[*index] == b'E';
+                *index += 1;
+
+                let negative = scan_sign(bytes, index);
+                let exponent_start = *index;
+                let (digits, separators) = scan_digits(bytes, index, |byte| byte.is_ascii_digit())
+                    .map_err(NumberParsingError::InvalidDigitSeparator)?;
+                exponent_separators = separators;
+
+                if digits.is_empty() {
+                    return Err(NumberParsingError::InvalidDecimalExponent(exponent_start));
+                }
+
+                let magnitude: i64 = digits.parse()
+                    .map_err(|_| NumberParsingError::InvalidDecimalExponent(exponent_start))?;
+
+                exponent = Some((if negative { -magnitude } else { magnitude }, is_uppercase));
+            }
+
+            if *index != bytes.len() {
+                return Err(NumberParsingError::InvalidDecimalNumber(*index));
+            }
+
+            let value: f64 = mantissa.parse()
+                .map_err(|_| NumberParsingError::InvalidDecimalNumber(0))?;
+
+            let mut number = DecimalNumber::new(value).with_separators(DigitSeparators {
+                integer: integer_separators,
+                fractional: fractional_separators,
+                exponent: exponent_separators,
+            });
+
+            if exponent.is_none() {
+                if let Some(exact_integer) = exact_integer_digits
+                    .and_then(|digits| digits.parse::<u64>().ok())
+                    .and_then(|integer| i64::try_from(integer).ok())
+                {
+                    number = number.with_exact_integer(exact_integer);
+                }
+            }
+
+            if let Some((exponent, is_uppercase)) = exponent {
+                number = number.with_exponent(exponent, is_uppercase);
+            }
+
+            number.into()
         };
 
         Ok(number)
@@ -278,6 +683,21 @@ mod test {
         }
     }
 
+    mod binary {
+        use super::*;
+
+        #[test]
+        fn set_uppercase_change() {
+            let initial_case = true;
+            let modified_case = !initial_case;
+            let mut number = BinaryNumber::new(1, initial_case);
+
+            number.set_uppercase(modified_case);
+
+            assert_eq!(number.is_b_uppercase(), modified_case);
+        }
+    }
+
     mod parse_number {
         use super::*;
 
@@ -312,8 +732,8 @@ mod test {
         }
 
         test_numbers!(
-            parse_zero("0") => DecimalNumber::new(0_f64),
-            parse_integer("123") => DecimalNumber::new(123_f64),
+            parse_zero("0") => DecimalNumber::new(0_f64).with_exact_integer(0),
+            parse_integer("123") => DecimalNumber::new(123_f64).with_exact_integer(123),
             parse_multiple_decimal("123.24") => DecimalNumber::new(123.24_f64),
             parse_float_with_trailing_dot("123.") => DecimalNumber::new(123_f64),
             parse_starting_with_dot(".123") => DecimalNumber::new(0.123_f64),
@@ -331,15 +751,55 @@ mod test {
             parse_hex_number_with_uppercase("0x12A") => HexNumber::new(298, false),
             parse_hex_number_with_mixed_case("0x1bF2A") => HexNumber::new(114_474, false),
             parse_hex_with_exponent("0x12p4") => HexNumber::new(18, false).with_exponent(4, false),
-            parse_hex_with_exponent_uppercase("0xABP3") => HexNumber::new(171, false).with_exponent(3, true)
+            parse_hex_with_exponent_uppercase("0xABP3") => HexNumber::new(171, false).with_exponent(3, true),
+            parse_hex_with_negative_exponent("0x1p-3") => HexNumber::new(1, false).with_exponent(-3, false),
+            parse_hex_with_fractional_part("0x1.8") => HexNumber::new(1, false).with_fractional_part(8, 1),
+            parse_hex_with_fractional_part_and_exponent("0x1.8p1") =>
+                HexNumber::new(1, false).with_fractional_part(8, 1).with_exponent(1, false),
+            parse_hex_with_fractional_part_and_negative_exponent("0xA.bP-2") =>
+                HexNumber::new(10, false).with_fractional_part(0xb, 1).with_exponent(-2, true),
+            parse_hex_without_integer_part("0x.8") => HexNumber::new(0, false).with_fractional_part(8, 1),
+            parse_hex_without_fractional_digits("0x8.") => HexNumber::new(8, false),
+            parse_binary_number("0b1010") => BinaryNumber::new(0b1010, false),
+            parse_uppercase_binary_number("0B1010") => BinaryNumber::new(0b1010, true),
+            parse_decimal_with_separators("1_000_000") => DecimalNumber::new(1_000_000_f64)
+                .with_separators(DigitSeparators {
+                    integer: vec![1, 4],
+                    fractional: Vec::new(),
+                    exponent: Vec::new(),
+                })
+                .with_exact_integer(1_000_000),
+            parse_hex_with_separators("0xFF_FF") => HexNumber::new(0xFF_FF, false)
+                .with_separators(DigitSeparators {
+                    integer: vec![2],
+                    fractional: Vec::new(),
+                    exponent: Vec::new(),
+                }),
+            parse_binary_with_separators("0b1010_0101") => BinaryNumber::new(0b1010_0101, false)
+                .with_separators(DigitSeparators {
+                    integer: vec![4],
+                    fractional: Vec::new(),
+                    exponent: Vec::new(),
+                })
         );
 
         test_parse_errors!(
-            parse_empty_string("") => NumberParsingError::InvalidDecimalNumber,
-            missing_exponent_value("1e") => NumberParsingError::InvalidDecimalExponent,
-            missing_negative_exponent_value("1e-") => NumberParsingError::InvalidDecimalExponent,
-            missing_hex_exponent_value("0x1p") => NumberParsingError::InvalidHexadecimalExponent,
-            negative_hex_exponent_value("0x1p-3") => NumberParsingError::InvalidHexadecimalExponent
+            parse_empty_string("") => NumberParsingError::InvalidDecimalNumber(0),
+            missing_exponent_value("1e") => NumberParsingError::InvalidDecimalExponent(2),
+            missing_negative_exponent_value("1e-") => NumberParsingError::InvalidDecimalExponent(3),
+            missing_hex_exponent_value("0x1p") => NumberParsingError::InvalidHexadecimalExponent(4),
+            missing_hex_digits("0x.") => NumberParsingError::InvalidHexadecimalNumber(3),
+            missing_hex_digits_with_exponent("0xp3") => NumberParsingError::InvalidHexadecimalNumber(2),
+            missing_binary_digits("0b") => NumberParsingError::InvalidBinaryNumber(2),
+            leading_separator("_1") => NumberParsingError::InvalidDigitSeparator(0),
+            trailing_separator("1_") => NumberParsingError::InvalidDigitSeparator(1),
+            doubled_separator("1__000") => NumberParsingError::InvalidDigitSeparator(2),
+            separator_adjacent_to_dot_before("1_.5") => NumberParsingError::InvalidDigitSeparator(1),
+            separator_adjacent_to_dot_after("1._5") => NumberParsingError::InvalidDigitSeparator(2),
+            separator_adjacent_to_exponent_marker("1_e5") => NumberParsingError::InvalidDigitSeparator(1),
+            separator_adjacent_to_prefix("0x_FF") => NumberParsingError::InvalidDigitSeparator(2),
+            trailing_garbage_after_decimal("123abc") => NumberParsingError::InvalidDecimalNumber(3),
+            trailing_garbage_after_hex("0x12g") => NumberParsingError::InvalidHexadecimalNumber(4)
         );
     }
 
@@ -351,7 +811,8 @@ mod test {
                 $(
                     #[test]
                     fn $name() {
-                        let number = NumberExpression::from($input);
+                        let number: NumberExpression = $input.parse()
+                            .expect("should be a valid number");
                         assert_eq!(number.compute_value(), $value as f64);
                     }
                 )*
@@ -370,7 +831,84 @@ mod test {
             float_with_exponent("10.5e2") => 10.5e2,
             hex_number("0x12") => 0x12,
             hex_number_with_letter("0x12a") => 0x12a,
-            hex_with_exponent("0x12p4") => 0x120
+            hex_with_exponent("0x12p4") => 0x120,
+            hex_with_negative_exponent("0x1p-3") => 0.125,
+            hex_with_fractional_part("0x1.8") => 1.5,
+            hex_with_fractional_part_and_exponent("0x1.8p1") => 3,
+            hex_without_integer_part("0x.8") => 0.5,
+            hex_with_exponent_beyond_i32_saturates("0x1p2147483648") => f64::INFINITY
+        );
+    }
+
+    mod exact_integer {
+        use super::*;
+
+        macro_rules! test_exact_integer {
+            ($($name:ident($input:literal) => $value:expr),*) => {
+                $(
+                    #[test]
+                    fn $name() {
+                        let number: NumberExpression = $input.parse()
+                            .expect("should be a valid number");
+                        assert_eq!(number.get_exact_integer(), $value);
+                    }
+                )*
+            };
+        }
+
+        test_exact_integer!(
+            zero("0") => Some(0),
+            integer("123") => Some(123),
+            integer_beyond_f64_precision("9007199254740993") => Some(9007199254740993),
+            integer_with_separators("1_000_000") => Some(1000000),
+            decimal_with_fractional_part("123.45") => None,
+            decimal_with_exponent("123e4") => None,
+            decimal_with_zero_fractional_part("123.0") => None,
+            hex_integer("0x12") => Some(0x12),
+            hex_with_fractional_part("0x1.8") => None,
+            hex_with_exponent("0x12p4") => None,
+            binary_integer("0b1010") => Some(0b1010)
+        );
+    }
+
+    mod minimal_form {
+        use super::*;
+
+        macro_rules! test_minimal_form {
+            ($($name:ident($input:literal) => $expect:literal),*) => {
+                $(
+                    #[test]
+                    fn $name() {
+                        let number: NumberExpression = $input.parse()
+                            .expect("should be a valid number");
+                        let minimal = number.minimal_form(true);
+
+                        let expect: NumberExpression = $expect.parse()
+                            .expect("expected literal should be a valid number");
+
+                        assert_eq!(
+                            minimal.compute_value().to_bits(),
+                            number.compute_value().to_bits(),
+                        );
+                        assert_eq!(minimal, expect);
+                    }
+                )*
+            };
+        }
+
+        test_minimal_form!(
+            decimal_picks_shorter_exponential("1_000_000") => "1e6",
+            hex_picks_shorter_decimal("0x0A") => "10",
+            hex_power_of_two_picks_shorter_hex_exponent("0x100000000") => "0x1p32",
+            decimal_ties_over_hex_exponent("0x120000") => "1179648",
+            decimal_exponent_picks_shorter_exponential("123e4") => "1.23e6",
+            small_fraction_prefers_decimal("0.125") => "0.125"
         );
+
+        #[test]
+        fn disabled_returns_the_same_literal() {
+            let number: NumberExpression = "1_000_000".parse().expect("should be a valid number");
+            assert_eq!(number.minimal_form(false), number);
+        }
     }
 }